@@ -0,0 +1,190 @@
+use enums::{CapStyle, JoinStyle};
+use error::{Error, GResult};
+use ffi::*;
+
+/// Builder around `GEOSBufferParams`, used to configure a buffer operation
+/// beyond the basic `width`/`quadsegs` pair exposed by [`GGeom::buffer`].
+///
+/// # Example
+///
+/// ```
+/// use geos::{BufferParams, CapStyle, GGeom};
+///
+/// let geom = GGeom::new("POINT (0 0)").expect("Invalid geometry");
+/// let params = BufferParams::builder()
+///     .end_cap_style(CapStyle::Square)
+///     .quadrant_segments(4)
+///     .build()
+///     .expect("Failed to build BufferParams");
+///
+/// let _buffered = geom.buffer_with_params(&params, 1.);
+/// ```
+pub struct BufferParams {
+    ptr: *mut GEOSBufferParams,
+}
+
+impl BufferParams {
+    pub fn builder() -> BufferParamsBuilder {
+        BufferParamsBuilder::default()
+    }
+
+    pub(crate) fn as_raw(&self) -> *const GEOSBufferParams {
+        self.ptr
+    }
+}
+
+impl Drop for BufferParams {
+    fn drop(&mut self) {
+        unsafe { GEOSBufferParams_destroy_r(get_context_handle(), self.ptr) };
+    }
+}
+
+impl GGeom {
+    /// Buffers `self` using the full configuration held by `params`.
+    pub fn buffer_with_params(&self, params: &BufferParams, width: f64) -> GResult<GGeom> {
+        unsafe {
+            GGeom::new_from_raw(GEOSBufferWithParams_r(get_context_handle(), self.as_raw(), params.as_raw(), width))
+        }
+    }
+
+    /// Buffers `self` with an explicit cap/join style, without going through
+    /// a [`BufferParams`] builder.
+    pub fn buffer_with_style(
+        &self,
+        width: f64,
+        quadrant_segments: i32,
+        end_cap_style: CapStyle,
+        join_style: JoinStyle,
+        mitre_limit: f64,
+    ) -> GResult<GGeom> {
+        unsafe {
+            GGeom::new_from_raw(GEOSBufferWithStyle_r(
+                get_context_handle(),
+                self.as_raw(),
+                width,
+                quadrant_segments,
+                end_cap_style.into(),
+                join_style.into(),
+                mitre_limit,
+            ))
+        }
+    }
+}
+
+/// Builder for [`BufferParams`].
+pub struct BufferParamsBuilder {
+    quadrant_segments: i32,
+    end_cap_style: CapStyle,
+    join_style: JoinStyle,
+    mitre_limit: f64,
+    single_sided: bool,
+}
+
+impl Default for BufferParamsBuilder {
+    fn default() -> Self {
+        BufferParamsBuilder {
+            quadrant_segments: 8,
+            end_cap_style: CapStyle::Round,
+            join_style: JoinStyle::Round,
+            mitre_limit: 5.0,
+            single_sided: false,
+        }
+    }
+}
+
+impl BufferParamsBuilder {
+    /// Sets the number of segments used to approximate a quarter circle.
+    pub fn quadrant_segments(mut self, quadrant_segments: i32) -> Self {
+        self.quadrant_segments = quadrant_segments;
+        self
+    }
+
+    /// Sets the end cap style.
+    pub fn end_cap_style(mut self, style: CapStyle) -> Self {
+        self.end_cap_style = style;
+        self
+    }
+
+    /// Sets the join style.
+    pub fn join_style(mut self, style: JoinStyle) -> Self {
+        self.join_style = style;
+        self
+    }
+
+    /// Sets the mitre limit, only used when `join_style` is [`JoinStyle::Mitre`].
+    pub fn mitre_limit(mut self, mitre_limit: f64) -> Self {
+        self.mitre_limit = mitre_limit;
+        self
+    }
+
+    /// Sets whether the buffer should only be generated on one side of the
+    /// input geometry.
+    pub fn single_sided(mut self, single_sided: bool) -> Self {
+        self.single_sided = single_sided;
+        self
+    }
+
+    pub fn build(self) -> GResult<BufferParams> {
+        unsafe {
+            let ptr = GEOSBufferParams_create_r(get_context_handle());
+            if ptr.is_null() {
+                return Err(Error::GenericError("GEOSBufferParams_create_r failed".to_owned()));
+            }
+            let params = BufferParams { ptr };
+            check_ret(
+                GEOSBufferParams_setQuadrantSegments_r(get_context_handle(), ptr, self.quadrant_segments),
+                "GEOSBufferParams_setQuadrantSegments_r failed",
+            )?;
+            check_ret(
+                GEOSBufferParams_setEndCapStyle_r(get_context_handle(), ptr, self.end_cap_style.into()),
+                "GEOSBufferParams_setEndCapStyle_r failed",
+            )?;
+            check_ret(
+                GEOSBufferParams_setJoinStyle_r(get_context_handle(), ptr, self.join_style.into()),
+                "GEOSBufferParams_setJoinStyle_r failed",
+            )?;
+            check_ret(
+                GEOSBufferParams_setMitreLimit_r(get_context_handle(), ptr, self.mitre_limit),
+                "GEOSBufferParams_setMitreLimit_r failed",
+            )?;
+            check_ret(
+                GEOSBufferParams_setSingleSided_r(get_context_handle(), ptr, self.single_sided as i32),
+                "GEOSBufferParams_setSingleSided_r failed",
+            )?;
+            Ok(params)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use buffer_params::BufferParams;
+    use enums::CapStyle;
+    use ffi::GGeom;
+
+    #[test]
+    fn buffer_with_params_square_cap_test() {
+        let geom = GGeom::new("LINESTRING(0 0, 10 0)").unwrap();
+        let params = BufferParams::builder()
+            .end_cap_style(CapStyle::Square)
+            .quadrant_segments(4)
+            .build()
+            .unwrap();
+
+        let buffered = geom.buffer_with_params(&params, 1.).unwrap();
+
+        assert!(buffered.is_valid());
+        assert!(buffered.get_area().unwrap() > 0.);
+    }
+
+    #[test]
+    fn buffer_with_style_test() {
+        use enums::JoinStyle;
+
+        let geom = GGeom::new("POINT(0 0)").unwrap();
+
+        let buffered = geom.buffer_with_style(1., 8, CapStyle::Round, JoinStyle::Round, 5.).unwrap();
+
+        assert!(buffered.is_valid());
+    }
+}
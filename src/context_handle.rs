@@ -3,14 +3,20 @@ use c_vec::CVec;
 use enums::{ByteOrder, Dimensions};
 use error::{Error, GResult};
 use ffi::*;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
 use std::sync::{Arc, Mutex};
 
 pub struct GContextHandle {
     ptr: GEOSContextHandle_t,
-    // boxed for stable address
     messages: Arc<Mutex<Messages>>,
-    notice_closure: Box<dyn Fn(&str)>,
-    error_closure: Box<dyn Fn(&str)>,
+    // raw pointers to the boxed closures registered with GEOS as notice/error
+    // handler user-data: they must stay heap-allocated at a fixed address, not
+    // move along with `self`, since GEOS keeps calling back into them for the
+    // lifetime of the context.
+    notice_data: *mut Box<dyn Fn(&str)>,
+    error_data: *mut Box<dyn Fn(&str)>,
 }
 
 #[derive(Default)]
@@ -35,22 +41,23 @@ impl GContextHandle {
             return Err(Error::GenericError("GEOS_init_r failed".to_owned()))
         }
         let messages = Arc::new(Mutex::new(Messages::default()));
-        let notice_closure = {
+        let notice_closure: Box<dyn Fn(&str)> = {
             let messages = messages.clone();
-            move |s: &str| messages.lock().unwrap().last_notice = Some(s.to_string())
+            Box::new(move |s: &str| messages.lock().unwrap().last_notice = Some(s.to_string()))
         };
-        let error_closure = {
+        let error_closure: Box<dyn Fn(&str)> = {
             let messages = messages.clone();
-            move |s: &str| messages.lock().unwrap().last_error = Some(s.to_string())
+            Box::new(move |s: &str| messages.lock().unwrap().last_error = Some(s.to_string()))
         };
-        let res = GContextHandle {
-            ptr,
-            messages,
-            notice_closure: Box::new(notice_closure),
-            error_closure: Box::new(error_closure),
-        };
-        // TODO: set handlers...
-        Ok(res)
+        // box again so the user-data pointer we hand to GEOS stays valid at a
+        // fixed heap address, whatever happens to `self` afterwards.
+        let notice_data = Box::into_raw(Box::new(notice_closure));
+        let error_data = Box::into_raw(Box::new(error_closure));
+        unsafe {
+            GEOSContext_setNoticeMessageHandler_r(ptr, Some(message_handler), notice_data as *mut c_void);
+            GEOSContext_setErrorMessageHandler_r(ptr, Some(message_handler), error_data as *mut c_void);
+        }
+        Ok(GContextHandle { ptr, messages, notice_data, error_data })
     }
 
     pub fn take_last_notice(&self) -> Option<String> {
@@ -219,9 +226,26 @@ impl GContextHandle {
 }
 
 impl Drop for GContextHandle {
-    fn drop<'a>(&'a mut self) {
-        unsafe { GEOS_finish_r(self.ptr) };
+    fn drop(&mut self) {
+        unsafe {
+            GEOSContext_setNoticeMessageHandler_r(self.ptr, None, ptr::null_mut());
+            GEOSContext_setErrorMessageHandler_r(self.ptr, None, ptr::null_mut());
+            GEOS_finish_r(self.ptr);
+            drop(Box::from_raw(self.notice_data));
+            drop(Box::from_raw(self.error_data));
+        }
+    }
+}
 
-        // TODO: cleanup handlers
+/// Trampoline registered as the notice/error handler on the GEOS context: GEOS
+/// calls this with the already-formatted message and the `user_data` pointer
+/// we gave it, which we reconstruct as the boxed closure to invoke.
+extern "C" fn message_handler(message: *const c_char, user_data: *mut c_void) {
+    if message.is_null() || user_data.is_null() {
+        return;
+    }
+    unsafe {
+        let callback = &*(user_data as *const Box<dyn Fn(&str)>);
+        callback(&CStr::from_ptr(message).to_string_lossy());
     }
 }
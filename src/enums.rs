@@ -0,0 +1,132 @@
+/// Byte order used when reading/writing WKB.
+///
+/// Maps to the `GEOS_WKB_BYTE_ORDER` GEOS enum.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteOrder {
+    BigEndian = 0,
+    LittleEndian = 1,
+}
+
+impl From<i32> for ByteOrder {
+    fn from(order: i32) -> ByteOrder {
+        match order {
+            0 => ByteOrder::BigEndian,
+            1 => ByteOrder::LittleEndian,
+            _ => panic!("Unknown ByteOrder value: {}", order),
+        }
+    }
+}
+
+impl From<ByteOrder> for i32 {
+    fn from(order: ByteOrder) -> i32 {
+        order as i32
+    }
+}
+
+/// Output dimensions used when reading/writing WKB/WKT.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimensions {
+    TwoD = 2,
+    ThreeD = 3,
+}
+
+impl From<i32> for Dimensions {
+    fn from(dimensions: i32) -> Dimensions {
+        match dimensions {
+            2 => Dimensions::TwoD,
+            3 => Dimensions::ThreeD,
+            _ => panic!("Unknown Dimensions value: {}", dimensions),
+        }
+    }
+}
+
+impl From<Dimensions> for i32 {
+    fn from(dimensions: Dimensions) -> i32 {
+        dimensions as i32
+    }
+}
+
+/// Cap style to use during a buffer operation.
+///
+/// Maps to the `GEOSBufCapStyles` GEOS enum.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapStyle {
+    Round = 1,
+    Flat = 2,
+    Square = 3,
+}
+
+impl From<i32> for CapStyle {
+    fn from(style: i32) -> CapStyle {
+        match style {
+            1 => CapStyle::Round,
+            2 => CapStyle::Flat,
+            3 => CapStyle::Square,
+            _ => panic!("Unknown CapStyle value: {}", style),
+        }
+    }
+}
+
+impl From<CapStyle> for i32 {
+    fn from(style: CapStyle) -> i32 {
+        style as i32
+    }
+}
+
+/// Join style to use during a buffer operation.
+///
+/// Maps to the `GEOSBufJoinStyles` GEOS enum.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinStyle {
+    Round = 1,
+    Mitre = 2,
+    Bevel = 3,
+}
+
+impl From<i32> for JoinStyle {
+    fn from(style: i32) -> JoinStyle {
+        match style {
+            1 => JoinStyle::Round,
+            2 => JoinStyle::Mitre,
+            3 => JoinStyle::Bevel,
+            _ => panic!("Unknown JoinStyle value: {}", style),
+        }
+    }
+}
+
+impl From<JoinStyle> for i32 {
+    fn from(style: JoinStyle) -> i32 {
+        style as i32
+    }
+}
+
+/// Identifies which GEOS predicate function produced a `0`/`1`/`2` result, so
+/// `check_geos_predicate` can turn GEOS's `2` ("exception") return value into
+/// the right error message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PredicateType {
+    Intersects,
+    Crosses,
+    Disjoint,
+    Touches,
+    Overlaps,
+    Within,
+    Equals,
+    Covers,
+    CoveredBy,
+    Contains,
+    ContainsProperly,
+    PreparedContains,
+    PreparedContainsProperly,
+    PreparedCovers,
+    PreparedIntersects,
+    PreparedWithin,
+    PreparedCrosses,
+    PreparedDisjoint,
+    PreparedTouches,
+    PreparedOverlaps,
+}
@@ -0,0 +1,31 @@
+use std::fmt;
+
+/// Error type returned by fallible GEOS operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A geometry could not be built or is not of the expected type/shape.
+    InvalidGeometry(String),
+    /// Any other failure reported by GEOS (a null pointer or failure code
+    /// returned by a `_r` call).
+    GenericError(String),
+}
+
+pub type GResult<T> = Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::InvalidGeometry(ref s) => write!(f, "Invalid geometry, {}", s),
+            Error::GenericError(ref s) => write!(f, "Generic error, {}", s),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::InvalidGeometry(ref s) => s,
+            Error::GenericError(ref s) => s,
+        }
+    }
+}
@@ -0,0 +1,642 @@
+//! Low-level bindings to the GEOS C API (`geos_c.h`) and the safe wrappers
+//! (`GGeom`, `CoordSeq`) built on top of them.
+//!
+//! Every `_r` function below takes an explicit `GEOSContextHandle_t` so GEOS
+//! can be driven from multiple threads, one context per thread. [`GGeom`]
+//! and friends use a lazily-created, thread-local context obtained through
+//! [`get_context_handle`]; [`crate::GContextHandle`] is a separate, explicit
+//! context for callers who want to control WKB settings or error/notice
+//! handlers directly.
+
+use enums::PredicateType;
+use error::{Error, GResult};
+use std::cell::RefCell;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_uint, c_void};
+
+#[allow(non_camel_case_types)]
+pub(crate) type GEOSContextHandle_t = *mut c_void;
+
+pub(crate) enum GEOSGeometry {}
+pub(crate) enum GEOSCoordSequence {}
+pub(crate) enum GEOSPreparedGeometry {}
+pub(crate) enum GEOSBufferParams {}
+pub(crate) enum GEOSWKTWriter {}
+
+#[allow(non_camel_case_types)]
+pub(crate) type GEOSMessageHandler_r = extern "C" fn(*const c_char, *mut c_void);
+
+#[link(name = "geos_c")]
+extern "C" {
+    pub(crate) fn GEOS_init_r() -> GEOSContextHandle_t;
+    pub(crate) fn GEOS_finish_r(handle: GEOSContextHandle_t);
+    pub(crate) fn GEOSContext_setNoticeMessageHandler_r(
+        handle: GEOSContextHandle_t,
+        nf: Option<GEOSMessageHandler_r>,
+        user_data: *mut c_void,
+    );
+    pub(crate) fn GEOSContext_setErrorMessageHandler_r(
+        handle: GEOSContextHandle_t,
+        ef: Option<GEOSMessageHandler_r>,
+        user_data: *mut c_void,
+    );
+
+    pub(crate) fn GEOS_getWKBOutputDims_r(handle: GEOSContextHandle_t) -> c_int;
+    pub(crate) fn GEOS_setWKBOutputDims_r(handle: GEOSContextHandle_t, dims: c_int) -> c_int;
+    pub(crate) fn GEOS_getWKBByteOrder_r(handle: GEOSContextHandle_t) -> c_int;
+    pub(crate) fn GEOS_setWKBByteOrder_r(handle: GEOSContextHandle_t, byte_order: c_int) -> c_int;
+
+    pub(crate) fn GEOSGeomFromWKB_buf_r(
+        handle: GEOSContextHandle_t,
+        wkb: *const u8,
+        size: usize,
+    ) -> *mut GEOSGeometry;
+    pub(crate) fn GEOSGeomToWKB_buf_r(
+        handle: GEOSContextHandle_t,
+        g: *const GEOSGeometry,
+        size: *mut usize,
+    ) -> *mut u8;
+    pub(crate) fn GEOSGeomFromHEX_buf_r(
+        handle: GEOSContextHandle_t,
+        hex: *const u8,
+        size: usize,
+    ) -> *mut GEOSGeometry;
+    pub(crate) fn GEOSGeomToHEX_buf_r(
+        handle: GEOSContextHandle_t,
+        g: *const GEOSGeometry,
+        size: *mut usize,
+    ) -> *mut u8;
+    pub(crate) fn GEOSFree_r(handle: GEOSContextHandle_t, buffer: *mut c_void);
+
+    pub(crate) fn GEOSGeomFromWKT_r(handle: GEOSContextHandle_t, wkt: *const c_char) -> *mut GEOSGeometry;
+    pub(crate) fn GEOSGeom_destroy_r(handle: GEOSContextHandle_t, g: *mut GEOSGeometry);
+    pub(crate) fn GEOSGeom_clone_r(handle: GEOSContextHandle_t, g: *const GEOSGeometry) -> *mut GEOSGeometry;
+    pub(crate) fn GEOSGeomTypeId_r(handle: GEOSContextHandle_t, g: *const GEOSGeometry) -> c_int;
+
+    pub(crate) fn GEOSCoordSeq_create_r(
+        handle: GEOSContextHandle_t,
+        size: c_uint,
+        dims: c_uint,
+    ) -> *mut GEOSCoordSequence;
+    pub(crate) fn GEOSCoordSeq_destroy_r(handle: GEOSContextHandle_t, cs: *mut GEOSCoordSequence);
+    pub(crate) fn GEOSCoordSeq_clone_r(
+        handle: GEOSContextHandle_t,
+        cs: *const GEOSCoordSequence,
+    ) -> *mut GEOSCoordSequence;
+    pub(crate) fn GEOSCoordSeq_setX_r(
+        handle: GEOSContextHandle_t,
+        cs: *mut GEOSCoordSequence,
+        idx: c_uint,
+        val: f64,
+    ) -> c_int;
+    pub(crate) fn GEOSCoordSeq_setY_r(
+        handle: GEOSContextHandle_t,
+        cs: *mut GEOSCoordSequence,
+        idx: c_uint,
+        val: f64,
+    ) -> c_int;
+    pub(crate) fn GEOSCoordSeq_getX_r(
+        handle: GEOSContextHandle_t,
+        cs: *const GEOSCoordSequence,
+        idx: c_uint,
+        val: *mut f64,
+    ) -> c_int;
+    pub(crate) fn GEOSCoordSeq_getY_r(
+        handle: GEOSContextHandle_t,
+        cs: *const GEOSCoordSequence,
+        idx: c_uint,
+        val: *mut f64,
+    ) -> c_int;
+    pub(crate) fn GEOSCoordSeq_getSize_r(
+        handle: GEOSContextHandle_t,
+        cs: *const GEOSCoordSequence,
+        size: *mut c_uint,
+    ) -> c_int;
+
+    pub(crate) fn GEOSGeom_createPoint_r(
+        handle: GEOSContextHandle_t,
+        cs: *mut GEOSCoordSequence,
+    ) -> *mut GEOSGeometry;
+    pub(crate) fn GEOSGeom_createLineString_r(
+        handle: GEOSContextHandle_t,
+        cs: *mut GEOSCoordSequence,
+    ) -> *mut GEOSGeometry;
+    pub(crate) fn GEOSGeom_createLinearRing_r(
+        handle: GEOSContextHandle_t,
+        cs: *mut GEOSCoordSequence,
+    ) -> *mut GEOSGeometry;
+    pub(crate) fn GEOSGeom_createPolygon_r(
+        handle: GEOSContextHandle_t,
+        shell: *mut GEOSGeometry,
+        holes: *mut *mut GEOSGeometry,
+        nholes: c_uint,
+    ) -> *mut GEOSGeometry;
+    pub(crate) fn GEOSGeom_createCollection_r(
+        handle: GEOSContextHandle_t,
+        geom_type: c_int,
+        geoms: *mut *mut GEOSGeometry,
+        ngeoms: c_uint,
+    ) -> *mut GEOSGeometry;
+
+    pub(crate) fn GEOSGetExteriorRing_r(
+        handle: GEOSContextHandle_t,
+        g: *const GEOSGeometry,
+    ) -> *const GEOSGeometry;
+    pub(crate) fn GEOSGetInteriorRingN_r(
+        handle: GEOSContextHandle_t,
+        g: *const GEOSGeometry,
+        n: c_int,
+    ) -> *const GEOSGeometry;
+    pub(crate) fn GEOSGetNumInteriorRings_r(handle: GEOSContextHandle_t, g: *const GEOSGeometry) -> c_int;
+    pub(crate) fn GEOSGetNumGeometries_r(handle: GEOSContextHandle_t, g: *const GEOSGeometry) -> c_int;
+    pub(crate) fn GEOSGetGeometryN_r(
+        handle: GEOSContextHandle_t,
+        g: *const GEOSGeometry,
+        n: c_int,
+    ) -> *const GEOSGeometry;
+    pub(crate) fn GEOSGeom_getCoordSeq_r(
+        handle: GEOSContextHandle_t,
+        g: *const GEOSGeometry,
+    ) -> *const GEOSCoordSequence;
+
+    pub(crate) fn GEOSisValid_r(handle: GEOSContextHandle_t, g: *const GEOSGeometry) -> c_char;
+    pub(crate) fn GEOSisRing_r(handle: GEOSContextHandle_t, g: *const GEOSGeometry) -> c_char;
+    pub(crate) fn GEOSEquals_r(
+        handle: GEOSContextHandle_t,
+        g1: *const GEOSGeometry,
+        g2: *const GEOSGeometry,
+    ) -> c_char;
+    pub(crate) fn GEOSContains_r(
+        handle: GEOSContextHandle_t,
+        g1: *const GEOSGeometry,
+        g2: *const GEOSGeometry,
+    ) -> c_char;
+    pub(crate) fn GEOSCovers_r(
+        handle: GEOSContextHandle_t,
+        g1: *const GEOSGeometry,
+        g2: *const GEOSGeometry,
+    ) -> c_char;
+    pub(crate) fn GEOSTouches_r(
+        handle: GEOSContextHandle_t,
+        g1: *const GEOSGeometry,
+        g2: *const GEOSGeometry,
+    ) -> c_char;
+    pub(crate) fn GEOSArea_r(handle: GEOSContextHandle_t, g: *const GEOSGeometry, area: *mut f64) -> c_int;
+
+    pub(crate) fn GEOSBuffer_r(
+        handle: GEOSContextHandle_t,
+        g: *const GEOSGeometry,
+        width: f64,
+        quadsegs: c_int,
+    ) -> *mut GEOSGeometry;
+    pub(crate) fn GEOSBufferWithStyle_r(
+        handle: GEOSContextHandle_t,
+        g: *const GEOSGeometry,
+        width: f64,
+        quadsegs: c_int,
+        end_cap_style: c_int,
+        join_style: c_int,
+        mitre_limit: f64,
+    ) -> *mut GEOSGeometry;
+    pub(crate) fn GEOSBufferWithParams_r(
+        handle: GEOSContextHandle_t,
+        g: *const GEOSGeometry,
+        params: *const GEOSBufferParams,
+        width: f64,
+    ) -> *mut GEOSGeometry;
+    pub(crate) fn GEOSBufferParams_create_r(handle: GEOSContextHandle_t) -> *mut GEOSBufferParams;
+    pub(crate) fn GEOSBufferParams_destroy_r(handle: GEOSContextHandle_t, params: *mut GEOSBufferParams);
+    pub(crate) fn GEOSBufferParams_setQuadrantSegments_r(
+        handle: GEOSContextHandle_t,
+        params: *mut GEOSBufferParams,
+        quadsegs: c_int,
+    ) -> c_int;
+    pub(crate) fn GEOSBufferParams_setEndCapStyle_r(
+        handle: GEOSContextHandle_t,
+        params: *mut GEOSBufferParams,
+        style: c_int,
+    ) -> c_int;
+    pub(crate) fn GEOSBufferParams_setJoinStyle_r(
+        handle: GEOSContextHandle_t,
+        params: *mut GEOSBufferParams,
+        style: c_int,
+    ) -> c_int;
+    pub(crate) fn GEOSBufferParams_setMitreLimit_r(
+        handle: GEOSContextHandle_t,
+        params: *mut GEOSBufferParams,
+        mitre_limit: f64,
+    ) -> c_int;
+    pub(crate) fn GEOSBufferParams_setSingleSided_r(
+        handle: GEOSContextHandle_t,
+        params: *mut GEOSBufferParams,
+        single_sided: c_int,
+    ) -> c_int;
+
+    pub(crate) fn GEOSPrepare_r(
+        handle: GEOSContextHandle_t,
+        g: *const GEOSGeometry,
+    ) -> *const GEOSPreparedGeometry;
+    pub(crate) fn GEOSPreparedGeom_destroy_r(handle: GEOSContextHandle_t, pg: *const GEOSPreparedGeometry);
+    pub(crate) fn GEOSPreparedContains_r(
+        handle: GEOSContextHandle_t,
+        pg: *const GEOSPreparedGeometry,
+        g: *const GEOSGeometry,
+    ) -> c_char;
+    pub(crate) fn GEOSPreparedContainsProperly_r(
+        handle: GEOSContextHandle_t,
+        pg: *const GEOSPreparedGeometry,
+        g: *const GEOSGeometry,
+    ) -> c_char;
+    pub(crate) fn GEOSPreparedCovers_r(
+        handle: GEOSContextHandle_t,
+        pg: *const GEOSPreparedGeometry,
+        g: *const GEOSGeometry,
+    ) -> c_char;
+    pub(crate) fn GEOSPreparedIntersects_r(
+        handle: GEOSContextHandle_t,
+        pg: *const GEOSPreparedGeometry,
+        g: *const GEOSGeometry,
+    ) -> c_char;
+    pub(crate) fn GEOSPreparedWithin_r(
+        handle: GEOSContextHandle_t,
+        pg: *const GEOSPreparedGeometry,
+        g: *const GEOSGeometry,
+    ) -> c_char;
+    pub(crate) fn GEOSPreparedCrosses_r(
+        handle: GEOSContextHandle_t,
+        pg: *const GEOSPreparedGeometry,
+        g: *const GEOSGeometry,
+    ) -> c_char;
+    pub(crate) fn GEOSPreparedDisjoint_r(
+        handle: GEOSContextHandle_t,
+        pg: *const GEOSPreparedGeometry,
+        g: *const GEOSGeometry,
+    ) -> c_char;
+    pub(crate) fn GEOSPreparedTouches_r(
+        handle: GEOSContextHandle_t,
+        pg: *const GEOSPreparedGeometry,
+        g: *const GEOSGeometry,
+    ) -> c_char;
+    pub(crate) fn GEOSPreparedOverlaps_r(
+        handle: GEOSContextHandle_t,
+        pg: *const GEOSPreparedGeometry,
+        g: *const GEOSGeometry,
+    ) -> c_char;
+
+    pub(crate) fn GEOSWKTWriter_create_r(handle: GEOSContextHandle_t) -> *mut GEOSWKTWriter;
+    pub(crate) fn GEOSWKTWriter_destroy_r(handle: GEOSContextHandle_t, writer: *mut GEOSWKTWriter);
+    pub(crate) fn GEOSWKTWriter_write_r(
+        handle: GEOSContextHandle_t,
+        writer: *mut GEOSWKTWriter,
+        g: *const GEOSGeometry,
+    ) -> *mut c_char;
+    pub(crate) fn GEOSWKTWriter_setRoundingPrecision_r(
+        handle: GEOSContextHandle_t,
+        writer: *mut GEOSWKTWriter,
+        precision: c_int,
+    );
+    pub(crate) fn GEOSWKTWriter_setTrim_r(handle: GEOSContextHandle_t, writer: *mut GEOSWKTWriter, trim: c_char);
+    pub(crate) fn GEOSWKTWriter_setOutputDimension_r(
+        handle: GEOSContextHandle_t,
+        writer: *mut GEOSWKTWriter,
+        dimensions: c_int,
+    );
+}
+
+thread_local! {
+    static CONTEXT: RefCell<GEOSContextHandle_t> = RefCell::new(unsafe { GEOS_init_r() });
+}
+
+/// Returns the thread-local GEOS context used internally by [`GGeom`] and
+/// friends, creating it on first use.
+pub(crate) fn get_context_handle() -> GEOSContextHandle_t {
+    CONTEXT.with(|ctx| *ctx.borrow())
+}
+
+/// Turns a GEOS predicate function's `0`/`1`/`2` return value into a
+/// `GResult<bool>`, with `2` meaning GEOS raised an exception.
+pub(crate) fn check_geos_predicate(val: i32, p: PredicateType) -> GResult<bool> {
+    match val {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(Error::GenericError(format!("{:?} failed", p))),
+    }
+}
+
+pub(crate) fn check_ret(val: c_int, err: &str) -> GResult<()> {
+    if val == 0 {
+        Err(Error::GenericError(err.to_owned()))
+    } else {
+        Ok(())
+    }
+}
+
+/// The GEOS geometry type, as returned by [`GGeom::geometry_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GGeomTypes {
+    Point = 0,
+    LineString = 1,
+    LinearRing = 2,
+    Polygon = 3,
+    MultiPoint = 4,
+    MultiLineString = 5,
+    MultiPolygon = 6,
+    GeometryCollection = 7,
+}
+
+impl GGeomTypes {
+    pub(crate) fn from_raw(val: c_int) -> GGeomTypes {
+        match val {
+            0 => GGeomTypes::Point,
+            1 => GGeomTypes::LineString,
+            2 => GGeomTypes::LinearRing,
+            3 => GGeomTypes::Polygon,
+            4 => GGeomTypes::MultiPoint,
+            5 => GGeomTypes::MultiLineString,
+            6 => GGeomTypes::MultiPolygon,
+            7 => GGeomTypes::GeometryCollection,
+            _ => panic!("Unknown GEOS geometry type: {}", val),
+        }
+    }
+}
+
+/// A coordinate sequence: the flat list of `(x, y)` pairs backing a
+/// [`GGeom`]. 2D only, matching what the rest of the crate needs.
+pub struct CoordSeq {
+    ptr: *mut GEOSCoordSequence,
+}
+
+impl CoordSeq {
+    pub fn new(size: u32, dims: u32) -> CoordSeq {
+        let ptr = unsafe { GEOSCoordSeq_create_r(get_context_handle(), size, dims) };
+        CoordSeq { ptr }
+    }
+
+    pub(crate) fn as_raw(&self) -> *mut GEOSCoordSequence {
+        self.ptr
+    }
+
+    pub fn set_x(&self, idx: u32, val: f64) -> GResult<()> {
+        let ret = unsafe { GEOSCoordSeq_setX_r(get_context_handle(), self.ptr, idx, val) };
+        check_ret(ret, "GEOSCoordSeq_setX_r failed")
+    }
+
+    pub fn set_y(&self, idx: u32, val: f64) -> GResult<()> {
+        let ret = unsafe { GEOSCoordSeq_setY_r(get_context_handle(), self.ptr, idx, val) };
+        check_ret(ret, "GEOSCoordSeq_setY_r failed")
+    }
+
+    pub fn get_x(&self, idx: u32) -> GResult<f64> {
+        let mut val = 0.;
+        let ret = unsafe { GEOSCoordSeq_getX_r(get_context_handle(), self.ptr, idx, &mut val) };
+        check_ret(ret, "GEOSCoordSeq_getX_r failed")?;
+        Ok(val)
+    }
+
+    pub fn get_y(&self, idx: u32) -> GResult<f64> {
+        let mut val = 0.;
+        let ret = unsafe { GEOSCoordSeq_getY_r(get_context_handle(), self.ptr, idx, &mut val) };
+        check_ret(ret, "GEOSCoordSeq_getY_r failed")?;
+        Ok(val)
+    }
+
+    pub fn len(&self) -> GResult<usize> {
+        let mut size = 0;
+        let ret = unsafe { GEOSCoordSeq_getSize_r(get_context_handle(), self.ptr, &mut size) };
+        check_ret(ret, "GEOSCoordSeq_getSize_r failed")?;
+        Ok(size as usize)
+    }
+
+    pub fn is_empty(&self) -> GResult<bool> {
+        Ok(self.len()? == 0)
+    }
+}
+
+impl Drop for CoordSeq {
+    fn drop(&mut self) {
+        unsafe { GEOSCoordSeq_destroy_r(get_context_handle(), self.ptr) };
+    }
+}
+
+/// A GEOS geometry.
+pub struct GGeom {
+    ptr: *mut GEOSGeometry,
+}
+
+fn check_geom_ptr(ptr: *mut GEOSGeometry) -> GResult<GGeom> {
+    if ptr.is_null() {
+        Err(Error::GenericError("GEOS geometry creation failed".to_owned()))
+    } else {
+        Ok(GGeom { ptr })
+    }
+}
+
+impl GGeom {
+    /// Builds a geometry from its WKT representation.
+    pub fn new(wkt: &str) -> GResult<GGeom> {
+        let c_str = CString::new(wkt).map_err(|e| Error::GenericError(e.to_string()))?;
+        let ptr = unsafe { GEOSGeomFromWKT_r(get_context_handle(), c_str.as_ptr()) };
+        check_geom_ptr(ptr)
+    }
+
+    pub(crate) unsafe fn new_from_raw(ptr: *mut GEOSGeometry) -> GResult<GGeom> {
+        check_geom_ptr(ptr)
+    }
+
+    pub(crate) fn as_raw(&self) -> *const GEOSGeometry {
+        self.ptr
+    }
+
+    pub fn create_point(cs: CoordSeq) -> GResult<GGeom> {
+        let ptr = unsafe { GEOSGeom_createPoint_r(get_context_handle(), cs.as_raw()) };
+        ::std::mem::forget(cs);
+        check_geom_ptr(ptr)
+    }
+
+    pub fn create_line_string(cs: CoordSeq) -> GResult<GGeom> {
+        let ptr = unsafe { GEOSGeom_createLineString_r(get_context_handle(), cs.as_raw()) };
+        ::std::mem::forget(cs);
+        check_geom_ptr(ptr)
+    }
+
+    pub fn create_linear_ring(cs: CoordSeq) -> GResult<GGeom> {
+        let ptr = unsafe { GEOSGeom_createLinearRing_r(get_context_handle(), cs.as_raw()) };
+        ::std::mem::forget(cs);
+        check_geom_ptr(ptr)
+    }
+
+    pub fn create_polygon(exterior: GGeom, interiors: Vec<GGeom>) -> GResult<GGeom> {
+        let nholes = interiors.len() as c_uint;
+        let mut holes: Vec<_> = interiors.into_iter().map(|g| {
+            let ptr = g.ptr;
+            ::std::mem::forget(g);
+            ptr
+        }).collect();
+        let shell_ptr = exterior.ptr;
+        ::std::mem::forget(exterior);
+        let ptr = unsafe {
+            GEOSGeom_createPolygon_r(get_context_handle(), shell_ptr, holes.as_mut_ptr(), nholes)
+        };
+        check_geom_ptr(ptr)
+    }
+
+    pub(crate) fn create_collection(geom_type: GGeomTypes, geoms: Vec<GGeom>) -> GResult<GGeom> {
+        let ngeoms = geoms.len() as c_uint;
+        let mut raw: Vec<_> = geoms.into_iter().map(|g| {
+            let ptr = g.ptr;
+            ::std::mem::forget(g);
+            ptr
+        }).collect();
+        let ptr = unsafe {
+            GEOSGeom_createCollection_r(get_context_handle(), geom_type as c_int, raw.as_mut_ptr(), ngeoms)
+        };
+        check_geom_ptr(ptr)
+    }
+
+    pub fn create_multipoint(geoms: Vec<GGeom>) -> GResult<GGeom> {
+        GGeom::create_collection(GGeomTypes::MultiPoint, geoms)
+    }
+
+    pub fn create_multilinestring(geoms: Vec<GGeom>) -> GResult<GGeom> {
+        GGeom::create_collection(GGeomTypes::MultiLineString, geoms)
+    }
+
+    pub fn create_multipolygon(geoms: Vec<GGeom>) -> GResult<GGeom> {
+        GGeom::create_collection(GGeomTypes::MultiPolygon, geoms)
+    }
+
+    pub fn create_geometry_collection(geoms: Vec<GGeom>) -> GResult<GGeom> {
+        GGeom::create_collection(GGeomTypes::GeometryCollection, geoms)
+    }
+
+    pub fn geometry_type(&self) -> GGeomTypes {
+        GGeomTypes::from_raw(unsafe { GEOSGeomTypeId_r(get_context_handle(), self.ptr) })
+    }
+
+    pub fn get_coord_seq(&self) -> GResult<CoordSeq> {
+        let borrowed = unsafe { GEOSGeom_getCoordSeq_r(get_context_handle(), self.ptr) };
+        if borrowed.is_null() {
+            return Err(Error::GenericError("GEOSGeom_getCoordSeq_r failed".to_owned()));
+        }
+        let cloned = unsafe { GEOSCoordSeq_clone_r(get_context_handle(), borrowed) };
+        if cloned.is_null() {
+            Err(Error::GenericError("GEOSCoordSeq_clone_r failed".to_owned()))
+        } else {
+            Ok(CoordSeq { ptr: cloned })
+        }
+    }
+
+    pub fn get_exterior_ring(&self) -> GResult<GGeom> {
+        let borrowed = unsafe { GEOSGetExteriorRing_r(get_context_handle(), self.ptr) };
+        if borrowed.is_null() {
+            return Err(Error::GenericError("GEOSGetExteriorRing_r failed".to_owned()));
+        }
+        let cloned = unsafe { GEOSGeom_clone_r(get_context_handle(), borrowed) };
+        check_geom_ptr(cloned)
+    }
+
+    pub fn get_num_interior_rings(&self) -> GResult<usize> {
+        let ret = unsafe { GEOSGetNumInteriorRings_r(get_context_handle(), self.ptr) };
+        if ret < 0 {
+            Err(Error::GenericError("GEOSGetNumInteriorRings_r failed".to_owned()))
+        } else {
+            Ok(ret as usize)
+        }
+    }
+
+    pub fn get_interior_ring_n(&self, n: u32) -> GResult<GGeom> {
+        let borrowed = unsafe { GEOSGetInteriorRingN_r(get_context_handle(), self.ptr, n as c_int) };
+        if borrowed.is_null() {
+            return Err(Error::GenericError("GEOSGetInteriorRingN_r failed".to_owned()));
+        }
+        let cloned = unsafe { GEOSGeom_clone_r(get_context_handle(), borrowed) };
+        check_geom_ptr(cloned)
+    }
+
+    pub fn get_num_geometries(&self) -> GResult<usize> {
+        let ret = unsafe { GEOSGetNumGeometries_r(get_context_handle(), self.ptr) };
+        if ret < 0 {
+            Err(Error::GenericError("GEOSGetNumGeometries_r failed".to_owned()))
+        } else {
+            Ok(ret as usize)
+        }
+    }
+
+    pub fn get_geometry_n(&self, n: usize) -> GResult<GGeom> {
+        let borrowed = unsafe { GEOSGetGeometryN_r(get_context_handle(), self.ptr, n as c_int) };
+        if borrowed.is_null() {
+            return Err(Error::GenericError("GEOSGetGeometryN_r failed".to_owned()));
+        }
+        let cloned = unsafe { GEOSGeom_clone_r(get_context_handle(), borrowed) };
+        check_geom_ptr(cloned)
+    }
+
+    pub fn get_x(&self) -> GResult<f64> {
+        self.get_coord_seq()?.get_x(0)
+    }
+
+    pub fn get_y(&self) -> GResult<f64> {
+        self.get_coord_seq()?.get_y(0)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        unsafe { GEOSisValid_r(get_context_handle(), self.ptr) == 1 }
+    }
+
+    pub fn is_ring(&self) -> GResult<bool> {
+        let ret = unsafe { GEOSisRing_r(get_context_handle(), self.ptr) };
+        match ret {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::GenericError("GEOSisRing_r failed".to_owned())),
+        }
+    }
+
+    pub fn equals(&self, other: &GGeom) -> GResult<bool> {
+        let ret = unsafe { GEOSEquals_r(get_context_handle(), self.ptr, other.ptr) };
+        check_geos_predicate(ret as _, PredicateType::Equals)
+    }
+
+    pub fn contains(&self, other: &GGeom) -> GResult<bool> {
+        let ret = unsafe { GEOSContains_r(get_context_handle(), self.ptr, other.ptr) };
+        check_geos_predicate(ret as _, PredicateType::Contains)
+    }
+
+    pub fn covers(&self, other: &GGeom) -> GResult<bool> {
+        let ret = unsafe { GEOSCovers_r(get_context_handle(), self.ptr, other.ptr) };
+        check_geos_predicate(ret as _, PredicateType::Covers)
+    }
+
+    pub fn touches(&self, other: &GGeom) -> GResult<bool> {
+        let ret = unsafe { GEOSTouches_r(get_context_handle(), self.ptr, other.ptr) };
+        check_geos_predicate(ret as _, PredicateType::Touches)
+    }
+
+    pub fn get_area(&self) -> GResult<f64> {
+        let mut area = 0.;
+        let ret = unsafe { GEOSArea_r(get_context_handle(), self.ptr, &mut area) };
+        check_ret(ret, "GEOSArea_r failed")?;
+        Ok(area)
+    }
+
+    /// Buffers `self` by `width`, approximating curves with `quadsegs`
+    /// segments per quarter circle.
+    pub fn buffer(&self, width: f64, quadsegs: i32) -> GResult<GGeom> {
+        let ptr = unsafe { GEOSBuffer_r(get_context_handle(), self.ptr, width, quadsegs as c_int) };
+        check_geom_ptr(ptr)
+    }
+}
+
+impl Drop for GGeom {
+    fn drop(&mut self) {
+        unsafe { GEOSGeom_destroy_r(get_context_handle(), self.ptr) };
+    }
+}
+
+impl Clone for GGeom {
+    fn clone(&self) -> GGeom {
+        let ptr = unsafe { GEOSGeom_clone_r(get_context_handle(), self.ptr) };
+        GGeom { ptr }
+    }
+}
+
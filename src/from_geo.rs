@@ -1,6 +1,6 @@
 extern crate geo;
 
-use self::geo::{LineString, MultiPolygon, Polygon, Point};
+use self::geo::{Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
 use ffi::{CoordSeq, GGeom};
 use error::Error;
 use std;
@@ -11,7 +11,7 @@ pub trait TryInto<T> {
     fn try_into(self) -> Result<T, Self::Err>;
 }
 
-fn create_coord_seq_from_vec<'a>(points: &'a[Point<f64>]) -> Result<CoordSeq, Error> {
+fn create_coord_seq_from_vec(points: &[Point<f64>]) -> Result<CoordSeq, Error> {
     create_coord_seq(points.iter(), points.len())
 }
 
@@ -26,7 +26,17 @@ where It: Iterator<Item = &'a Point<f64>> {
     Ok(coord_seq)
 }
 
-impl<'a> TryInto<GGeom> for &'a LineString<f64> {
+impl TryInto<GGeom> for &Point<f64> {
+    type Err = Error;
+
+    fn try_into(self) -> Result<GGeom, Self::Err> {
+        let coord_seq = create_coord_seq_from_vec(&[*self])?;
+
+        GGeom::create_point(coord_seq)
+    }
+}
+
+impl TryInto<GGeom> for &LineString<f64> {
     type Err = Error;
 
     fn try_into(self) -> Result<GGeom, Self::Err> {
@@ -65,7 +75,7 @@ impl<'a> TryInto<GGeom> for &'a LineRing<'a> {
     }
 }
 
-impl<'a> TryInto<GGeom> for &'a Polygon<f64> {
+impl TryInto<GGeom> for &Polygon<f64> {
     type Err = Error;
 
     fn try_into(self) -> Result<GGeom, Self::Err> {
@@ -80,7 +90,7 @@ impl<'a> TryInto<GGeom> for &'a Polygon<f64> {
     }
 }
 
-impl<'a> TryInto<GGeom> for &'a MultiPolygon<f64> {
+impl TryInto<GGeom> for &MultiPolygon<f64> {
     type Err = Error;
 
     fn try_into(self) -> Result<GGeom, Self::Err> {
@@ -93,13 +103,113 @@ impl<'a> TryInto<GGeom> for &'a MultiPolygon<f64> {
     }
 }
 
+impl TryInto<GGeom> for &MultiPoint<f64> {
+    type Err = Error;
+
+    fn try_into(self) -> Result<GGeom, Self::Err> {
+        let points: Vec<_> = self.0
+            .iter()
+            .map(|p| p.try_into())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        GGeom::create_multipoint(points)
+    }
+}
+
+impl TryInto<GGeom> for &MultiLineString<f64> {
+    type Err = Error;
+
+    fn try_into(self) -> Result<GGeom, Self::Err> {
+        let lines: Vec<_> = self.0
+            .iter()
+            .map(|l| l.try_into())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        GGeom::create_multilinestring(lines)
+    }
+}
+
+impl TryInto<GGeom> for &Geometry<f64> {
+    type Err = Error;
+
+    fn try_into(self) -> Result<GGeom, Self::Err> {
+        match *self {
+            Geometry::Point(ref p) => p.try_into(),
+            Geometry::LineString(ref l) => l.try_into(),
+            Geometry::Polygon(ref p) => p.try_into(),
+            Geometry::MultiPoint(ref p) => p.try_into(),
+            Geometry::MultiLineString(ref l) => l.try_into(),
+            Geometry::MultiPolygon(ref p) => p.try_into(),
+            Geometry::GeometryCollection(ref g) => g.try_into(),
+            _ => Err(Error::InvalidGeometry("Unsupported geo geometry type".into())),
+        }
+    }
+}
+
+impl TryInto<GGeom> for &GeometryCollection<f64> {
+    type Err = Error;
+
+    fn try_into(self) -> Result<GGeom, Self::Err> {
+        let geometries: Vec<_> = self.0
+            .iter()
+            .map(|g| g.try_into())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        GGeom::create_geometry_collection(geometries)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use from_geo::geo::{LineString, MultiPolygon, Point, Polygon};
+    use from_geo::geo::{
+        Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+    };
     use ffi::GGeom;
     use from_geo::TryInto;
     use super::LineRing;
 
+    #[test]
+    fn point_test() {
+        let p = Point::new(1., 2.);
+
+        let geom: GGeom = (&p).try_into().unwrap();
+
+        assert_eq!(geom.get_x().unwrap(), 1.);
+        assert_eq!(geom.get_y().unwrap(), 2.);
+    }
+
+    #[test]
+    fn multipoint_test() {
+        let mp = MultiPoint(vec![Point::new(0., 0.), Point::new(1., 1.)]);
+
+        let geom: GGeom = (&mp).try_into().unwrap();
+
+        assert_eq!(geom.get_num_geometries().unwrap(), 2);
+    }
+
+    #[test]
+    fn multilinestring_test() {
+        let ml = MultiLineString(vec![
+            LineString(vec![Point::new(0., 0.), Point::new(1., 1.)]),
+            LineString(vec![Point::new(2., 2.), Point::new(3., 3.)]),
+        ]);
+
+        let geom: GGeom = (&ml).try_into().unwrap();
+
+        assert_eq!(geom.get_num_geometries().unwrap(), 2);
+    }
+
+    #[test]
+    fn geometry_collection_test() {
+        let point = Geometry::Point(Point::new(0., 0.));
+        let line = Geometry::LineString(LineString(vec![Point::new(0., 0.), Point::new(1., 1.)]));
+        let gc = GeometryCollection(vec![point, line]);
+
+        let geom: GGeom = (&gc).try_into().unwrap();
+
+        assert_eq!(geom.get_num_geometries().unwrap(), 2);
+    }
+
     #[test]
     fn polygon_contains_test() {
         let exterior = LineString(vec![
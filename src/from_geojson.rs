@@ -0,0 +1,186 @@
+//! Conversion to and from the [`geojson`](https://docs.rs/geojson) crate's
+//! types, mirroring what [`from_geo`] does for `geo`. Gated behind the `json`
+//! cargo feature so default builds don't pull in the `geojson` dependency.
+extern crate geojson;
+
+use self::geojson::{Geometry, PolygonType, Position, Value};
+use ffi::{CoordSeq, GGeom, GGeomTypes};
+use error::{Error, GResult};
+use from_geo::TryInto;
+
+fn create_coord_seq_from_positions(positions: &[Position]) -> GResult<CoordSeq> {
+    let coord_seq = CoordSeq::new(positions.len() as u32, 2);
+    for (i, p) in positions.iter().enumerate() {
+        let i = i as u32;
+        coord_seq.set_x(i, p[0])?;
+        coord_seq.set_y(i, p[1])?;
+    }
+    Ok(coord_seq)
+}
+
+fn create_polygon(rings: &PolygonType) -> GResult<GGeom> {
+    let exterior = rings.first().ok_or_else(|| {
+        Error::InvalidGeometry("a GeoJSON Polygon needs at least an exterior ring".into())
+    })?;
+    let exterior = GGeom::create_linear_ring(create_coord_seq_from_positions(exterior)?)?;
+    let interiors = rings[1..]
+        .iter()
+        .map(|r| GGeom::create_linear_ring(create_coord_seq_from_positions(r)?))
+        .collect::<GResult<Vec<_>>>()?;
+
+    GGeom::create_polygon(exterior, interiors)
+}
+
+impl TryInto<GGeom> for &Geometry {
+    type Err = Error;
+
+    fn try_into(self) -> Result<GGeom, Self::Err> {
+        match self.value {
+            Value::Point(ref c) => GGeom::create_point(create_coord_seq_from_positions(std::slice::from_ref(c))?),
+            Value::MultiPoint(ref pts) => {
+                let points = pts
+                    .iter()
+                    .map(|c| GGeom::create_point(create_coord_seq_from_positions(std::slice::from_ref(c))?))
+                    .collect::<GResult<Vec<_>>>()?;
+                GGeom::create_multipoint(points)
+            }
+            Value::LineString(ref c) => GGeom::create_line_string(create_coord_seq_from_positions(c)?),
+            Value::MultiLineString(ref lines) => {
+                let lines = lines
+                    .iter()
+                    .map(|l| GGeom::create_line_string(create_coord_seq_from_positions(l)?))
+                    .collect::<GResult<Vec<_>>>()?;
+                GGeom::create_multilinestring(lines)
+            }
+            Value::Polygon(ref rings) => create_polygon(rings),
+            Value::MultiPolygon(ref polygons) => {
+                let polygons = polygons
+                    .iter()
+                    .map(create_polygon)
+                    .collect::<GResult<Vec<_>>>()?;
+                GGeom::create_multipolygon(polygons)
+            }
+            Value::GeometryCollection(ref geometries) => {
+                let geometries = geometries
+                    .iter()
+                    .map(|g| g.try_into())
+                    .collect::<GResult<Vec<_>>>()?;
+                GGeom::create_geometry_collection(geometries)
+            }
+        }
+    }
+}
+
+fn coord_seq_to_position(coord_seq: &CoordSeq, i: u32) -> GResult<Position> {
+    Ok(vec![coord_seq.get_x(i)?, coord_seq.get_y(i)?])
+}
+
+fn coord_seq_to_positions(coord_seq: &CoordSeq) -> GResult<Vec<Position>> {
+    let len = coord_seq.len()? as u32;
+    (0..len).map(|i| coord_seq_to_position(coord_seq, i)).collect()
+}
+
+fn polygon_to_rings(g: &GGeom) -> GResult<PolygonType> {
+    let mut rings = vec![coord_seq_to_positions(&g.get_exterior_ring()?.get_coord_seq()?)?];
+    let nb_interiors = g.get_num_interior_rings()?;
+    for n in 0..nb_interiors {
+        rings.push(coord_seq_to_positions(&g.get_interior_ring_n(n as u32)?.get_coord_seq()?)?);
+    }
+    Ok(rings)
+}
+
+/// Converts a [`GGeom`] into a GeoJSON [`Value`].
+pub fn try_into_geojson_value(g: &GGeom) -> GResult<Value> {
+    match g.geometry_type() {
+        GGeomTypes::Point => {
+            let p = coord_seq_to_position(&g.get_coord_seq()?, 0)?;
+            Ok(Value::Point(p))
+        }
+        GGeomTypes::LineString | GGeomTypes::LinearRing => {
+            Ok(Value::LineString(coord_seq_to_positions(&g.get_coord_seq()?)?))
+        }
+        GGeomTypes::Polygon => Ok(Value::Polygon(polygon_to_rings(g)?)),
+        GGeomTypes::MultiPoint => {
+            let nb_geometries = g.get_num_geometries()?;
+            let points = (0..nb_geometries)
+                .map(|n| coord_seq_to_position(&g.get_geometry_n(n)?.get_coord_seq()?, 0))
+                .collect::<GResult<Vec<_>>>()?;
+            Ok(Value::MultiPoint(points))
+        }
+        GGeomTypes::MultiLineString => {
+            let nb_geometries = g.get_num_geometries()?;
+            let lines = (0..nb_geometries)
+                .map(|n| coord_seq_to_positions(&g.get_geometry_n(n)?.get_coord_seq()?))
+                .collect::<GResult<Vec<_>>>()?;
+            Ok(Value::MultiLineString(lines))
+        }
+        GGeomTypes::MultiPolygon => {
+            let nb_geometries = g.get_num_geometries()?;
+            let polygons = (0..nb_geometries)
+                .map(|n| polygon_to_rings(&g.get_geometry_n(n)?))
+                .collect::<GResult<Vec<_>>>()?;
+            Ok(Value::MultiPolygon(polygons))
+        }
+        GGeomTypes::GeometryCollection => {
+            let nb_geometries = g.get_num_geometries()?;
+            let geometries = (0..nb_geometries)
+                .map(|n| {
+                    let geom = g.get_geometry_n(n)?;
+                    Ok(Geometry::new(try_into_geojson_value(&geom)?))
+                })
+                .collect::<GResult<Vec<_>>>()?;
+            Ok(Value::GeometryCollection(geometries))
+        }
+    }
+}
+
+impl TryInto<Value> for &GGeom {
+    type Err = Error;
+
+    fn try_into(self) -> Result<Value, Self::Err> {
+        try_into_geojson_value(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use from_geojson::geojson::{Geometry, Value};
+    use ffi::GGeom;
+    use from_geo::TryInto;
+
+    #[test]
+    fn point_round_trip_test() {
+        let geojson_geom = Geometry::new(Value::Point(vec![1., 2.]));
+
+        let geom: GGeom = (&geojson_geom).try_into().unwrap();
+        let back: Value = (&geom).try_into().unwrap();
+
+        assert_eq!(back, Value::Point(vec![1., 2.]));
+    }
+
+    #[test]
+    fn polygon_round_trip_test() {
+        let rings = vec![vec![
+            vec![0., 0.],
+            vec![0., 1.],
+            vec![1., 1.],
+            vec![1., 0.],
+            vec![0., 0.],
+        ]];
+        let geojson_geom = Geometry::new(Value::Polygon(rings.clone()));
+
+        let geom: GGeom = (&geojson_geom).try_into().unwrap();
+        let back: Value = (&geom).try_into().unwrap();
+
+        assert_eq!(back, Value::Polygon(rings));
+    }
+
+    #[test]
+    fn polygon_without_exterior_is_an_error_test() {
+        let geojson_geom = Geometry::new(Value::Polygon(vec![]));
+
+        let geom: Result<GGeom, _> = (&geojson_geom).try_into();
+
+        assert!(geom.is_err());
+    }
+}
@@ -0,0 +1,164 @@
+extern crate geo;
+
+use self::geo::{Geometry, GeometryCollection, LineString, MultiPolygon, Point, Polygon};
+use ffi::{CoordSeq, GGeom, GGeomTypes};
+use error::Error;
+use from_geo::TryInto;
+
+fn create_point_from_coord_seq(coord_seq: &CoordSeq) -> Result<Point<f64>, Error> {
+    Ok(Point::new(coord_seq.get_x(0)?, coord_seq.get_y(0)?))
+}
+
+fn create_line_string_from_coord_seq(coord_seq: &CoordSeq) -> Result<LineString<f64>, Error> {
+    let nb_points = coord_seq.len()?;
+    let mut points = Vec::with_capacity(nb_points);
+    for i in 0..nb_points {
+        let i = i as u32;
+        points.push(Point::new(coord_seq.get_x(i)?, coord_seq.get_y(i)?));
+    }
+    // Every point is copied as-is: a GEOS LinearRing is closed (first == last),
+    // so its closing point is kept in the resulting geo::LineString.
+    Ok(LineString(points))
+}
+
+impl TryInto<Point<f64>> for &GGeom {
+    type Err = Error;
+
+    fn try_into(self) -> Result<Point<f64>, Self::Err> {
+        match self.geometry_type() {
+            GGeomTypes::Point => create_point_from_coord_seq(&self.get_coord_seq()?),
+            _ => Err(Error::InvalidGeometry("impossible to convert geometry to a geo Point".into())),
+        }
+    }
+}
+
+impl TryInto<LineString<f64>> for &GGeom {
+    type Err = Error;
+
+    fn try_into(self) -> Result<LineString<f64>, Self::Err> {
+        match self.geometry_type() {
+            GGeomTypes::LineString | GGeomTypes::LinearRing => {
+                create_line_string_from_coord_seq(&self.get_coord_seq()?)
+            }
+            _ => Err(Error::InvalidGeometry("impossible to convert geometry to a geo LineString".into())),
+        }
+    }
+}
+
+impl TryInto<Polygon<f64>> for &GGeom {
+    type Err = Error;
+
+    fn try_into(self) -> Result<Polygon<f64>, Self::Err> {
+        if self.geometry_type() != GGeomTypes::Polygon {
+            return Err(Error::InvalidGeometry("impossible to convert geometry to a geo Polygon".into()));
+        }
+
+        let exterior: LineString<f64> = (&self.get_exterior_ring()?).try_into()?;
+        let nb_interiors = self.get_num_interior_rings()?;
+        let interiors = (0..nb_interiors)
+            .map(|n| (&self.get_interior_ring_n(n as u32)?).try_into())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Polygon::new(exterior, interiors))
+    }
+}
+
+impl TryInto<MultiPolygon<f64>> for &GGeom {
+    type Err = Error;
+
+    fn try_into(self) -> Result<MultiPolygon<f64>, Self::Err> {
+        if self.geometry_type() != GGeomTypes::MultiPolygon {
+            return Err(Error::InvalidGeometry("impossible to convert geometry to a geo MultiPolygon".into()));
+        }
+
+        let nb_geometries = self.get_num_geometries()?;
+        let polygons = (0..nb_geometries)
+            .map(|n| (&self.get_geometry_n(n)?).try_into())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(MultiPolygon(polygons))
+    }
+}
+
+fn try_into_geometry(g: &GGeom) -> Result<Geometry<f64>, Error> {
+    match g.geometry_type() {
+        GGeomTypes::Point => g.try_into().map(Geometry::Point),
+        GGeomTypes::LineString | GGeomTypes::LinearRing => g.try_into().map(Geometry::LineString),
+        GGeomTypes::Polygon => g.try_into().map(Geometry::Polygon),
+        GGeomTypes::MultiPolygon => g.try_into().map(Geometry::MultiPolygon),
+        GGeomTypes::GeometryCollection => g.try_into().map(Geometry::GeometryCollection),
+        _ => Err(Error::InvalidGeometry("unsupported geometry type in a GeometryCollection".into())),
+    }
+}
+
+impl TryInto<GeometryCollection<f64>> for &GGeom {
+    type Err = Error;
+
+    fn try_into(self) -> Result<GeometryCollection<f64>, Self::Err> {
+        if self.geometry_type() != GGeomTypes::GeometryCollection {
+            return Err(Error::InvalidGeometry("impossible to convert geometry to a geo GeometryCollection".into()));
+        }
+
+        let nb_geometries = self.get_num_geometries()?;
+        let geometries = (0..nb_geometries)
+            .map(|n| try_into_geometry(&self.get_geometry_n(n)?))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(GeometryCollection(geometries))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use from_geos::geo::{LineString, MultiPolygon, Point, Polygon};
+    use ffi::GGeom;
+    use from_geo::TryInto;
+
+    #[test]
+    fn point_round_trip_test() {
+        let p = Point::new(1., 2.);
+
+        let geom: GGeom = (&p).try_into().unwrap();
+        let back: Point<f64> = (&geom).try_into().unwrap();
+
+        assert_eq!(p, back);
+    }
+
+    #[test]
+    fn line_string_keeps_closing_point_test() {
+        // GEOS LinearRings are always closed (first == last); the reverse
+        // conversion must preserve that closing point, since `LineRing`
+        // (from_geo) expects it when going back the other way.
+        let exterior = LineString(vec![
+            Point::new(0., 0.),
+            Point::new(0., 1.),
+            Point::new(1., 1.),
+            Point::new(0., 0.),
+        ]);
+        let p = Polygon::new(exterior.clone(), vec![]);
+
+        let geom: GGeom = (&p).try_into().unwrap();
+        let back: Polygon<f64> = (&geom).try_into().unwrap();
+
+        assert_eq!(back.exterior.0.first(), back.exterior.0.last());
+        assert_eq!(back.exterior, exterior);
+    }
+
+    #[test]
+    fn multipolygon_round_trip_test() {
+        let exterior = LineString(vec![
+            Point::new(0., 0.),
+            Point::new(0., 1.),
+            Point::new(1., 1.),
+            Point::new(1., 0.),
+            Point::new(0., 0.),
+        ]);
+        let p = Polygon::new(exterior, vec![]);
+        let mp = MultiPolygon(vec![p]);
+
+        let geom: GGeom = (&mp).try_into().unwrap();
+        let back: MultiPolygon<f64> = (&geom).try_into().unwrap();
+
+        assert_eq!(mp, back);
+    }
+}
@@ -0,0 +1,22 @@
+extern crate c_vec;
+extern crate libc;
+
+mod buffer_params;
+mod context_handle;
+mod enums;
+mod error;
+mod ffi;
+pub mod from_geo;
+#[cfg(feature = "json")]
+pub mod from_geojson;
+pub mod from_geos;
+mod prepared_geometry;
+mod wkt_writer;
+
+pub use buffer_params::{BufferParams, BufferParamsBuilder};
+pub use context_handle::GContextHandle;
+pub use enums::*;
+pub use error::{Error, GResult};
+pub use ffi::{CoordSeq, GGeom, GGeomTypes};
+pub use prepared_geometry::PreparedGeometry;
+pub use wkt_writer::WKTWriter;
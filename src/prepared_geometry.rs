@@ -0,0 +1,132 @@
+use enums::PredicateType;
+use error::{Error, GResult};
+use ffi::*;
+use std::rc::Rc;
+
+/// A prepared geometry, built from a [`GGeom`] to speed up repeated predicate
+/// checks (`contains`, `intersects`, ...) against many other geometries.
+///
+/// Preparing a geometry builds an internal spatial index once; every predicate
+/// called afterwards reuses it instead of rebuilding it on each call, which is
+/// a significant speedup when the same geometry is tested against many
+/// candidates.
+pub struct PreparedGeometry {
+    ptr: *const GEOSPreparedGeometry,
+    // keeps the source geometry alive as long as the prepared geometry exists,
+    // since GEOS only stores a reference to it.
+    _source: Rc<GGeom>,
+}
+
+impl PreparedGeometry {
+    pub(crate) fn new(g: Rc<GGeom>) -> GResult<PreparedGeometry> {
+        let ptr = unsafe { GEOSPrepare_r(get_context_handle(), g.as_raw()) };
+        if ptr.is_null() {
+            Err(Error::GenericError("GEOSPrepare_r failed".to_owned()))
+        } else {
+            Ok(PreparedGeometry { ptr, _source: g })
+        }
+    }
+
+    /// Returns `true` if `self` contains `other`.
+    pub fn contains(&self, other: &GGeom) -> GResult<bool> {
+        let ret = unsafe { GEOSPreparedContains_r(get_context_handle(), self.ptr, other.as_raw()) };
+        check_geos_predicate(ret as _, PredicateType::PreparedContains)
+    }
+
+    /// Returns `true` if every point of `other` lies in the interior of `self`,
+    /// i.e. `self` contains `other` and no point of `other` is on the boundary
+    /// of `self`.
+    pub fn contains_properly(&self, other: &GGeom) -> GResult<bool> {
+        let ret = unsafe {
+            GEOSPreparedContainsProperly_r(get_context_handle(), self.ptr, other.as_raw())
+        };
+        check_geos_predicate(ret as _, PredicateType::PreparedContainsProperly)
+    }
+
+    /// Returns `true` if `self` covers `other`.
+    pub fn covers(&self, other: &GGeom) -> GResult<bool> {
+        let ret = unsafe { GEOSPreparedCovers_r(get_context_handle(), self.ptr, other.as_raw()) };
+        check_geos_predicate(ret as _, PredicateType::PreparedCovers)
+    }
+
+    /// Returns `true` if `self` intersects `other`.
+    pub fn intersects(&self, other: &GGeom) -> GResult<bool> {
+        let ret = unsafe {
+            GEOSPreparedIntersects_r(get_context_handle(), self.ptr, other.as_raw())
+        };
+        check_geos_predicate(ret as _, PredicateType::PreparedIntersects)
+    }
+
+    /// Returns `true` if `self` is within `other`.
+    pub fn within(&self, other: &GGeom) -> GResult<bool> {
+        let ret = unsafe { GEOSPreparedWithin_r(get_context_handle(), self.ptr, other.as_raw()) };
+        check_geos_predicate(ret as _, PredicateType::PreparedWithin)
+    }
+
+    /// Returns `true` if `self` crosses `other`.
+    pub fn crosses(&self, other: &GGeom) -> GResult<bool> {
+        let ret = unsafe { GEOSPreparedCrosses_r(get_context_handle(), self.ptr, other.as_raw()) };
+        check_geos_predicate(ret as _, PredicateType::PreparedCrosses)
+    }
+
+    /// Returns `true` if `self` is disjoint from `other`.
+    pub fn disjoint(&self, other: &GGeom) -> GResult<bool> {
+        let ret = unsafe {
+            GEOSPreparedDisjoint_r(get_context_handle(), self.ptr, other.as_raw())
+        };
+        check_geos_predicate(ret as _, PredicateType::PreparedDisjoint)
+    }
+
+    /// Returns `true` if `self` touches `other`.
+    pub fn touches(&self, other: &GGeom) -> GResult<bool> {
+        let ret = unsafe { GEOSPreparedTouches_r(get_context_handle(), self.ptr, other.as_raw()) };
+        check_geos_predicate(ret as _, PredicateType::PreparedTouches)
+    }
+
+    /// Returns `true` if `self` overlaps `other`.
+    pub fn overlaps(&self, other: &GGeom) -> GResult<bool> {
+        let ret = unsafe {
+            GEOSPreparedOverlaps_r(get_context_handle(), self.ptr, other.as_raw())
+        };
+        check_geos_predicate(ret as _, PredicateType::PreparedOverlaps)
+    }
+}
+
+impl Drop for PreparedGeometry {
+    fn drop(&mut self) {
+        unsafe { GEOSPreparedGeom_destroy_r(get_context_handle(), self.ptr) };
+    }
+}
+
+impl GGeom {
+    /// Prepares `self` for fast repeated predicate checks against many other
+    /// geometries, building a cached spatial index once (see
+    /// [`PreparedGeometry`]).
+    pub fn to_prepared_geom(&self) -> GResult<PreparedGeometry> {
+        PreparedGeometry::new(Rc::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ffi::GGeom;
+
+    #[test]
+    fn prepared_contains_test() {
+        let geom = GGeom::new("POLYGON((0 0, 0 10, 10 10, 10 0, 0 0))").unwrap();
+        let point = GGeom::new("POINT(5 5)").unwrap();
+        let prepared = geom.to_prepared_geom().unwrap();
+
+        assert!(prepared.contains(&point).unwrap());
+        assert!(!prepared.contains(&GGeom::new("POINT(50 50)").unwrap()).unwrap());
+    }
+
+    #[test]
+    fn prepared_intersects_test() {
+        let geom = GGeom::new("LINESTRING(0 0, 10 10)").unwrap();
+        let prepared = geom.to_prepared_geom().unwrap();
+
+        assert!(prepared.intersects(&GGeom::new("LINESTRING(0 10, 10 0)").unwrap()).unwrap());
+        assert!(prepared.disjoint(&GGeom::new("LINESTRING(20 20, 30 30)").unwrap()).unwrap());
+    }
+}
@@ -0,0 +1,99 @@
+use enums::Dimensions;
+use error::{Error, GResult};
+use ffi::*;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+/// Writer used to convert [`GGeom`] to WKT, with control over the rounding
+/// precision, whether trailing zeros are trimmed, and the output dimensions.
+///
+/// # Example
+///
+/// ```
+/// use geos::{GGeom, WKTWriter};
+///
+/// let geom = GGeom::new("POINT (2.5682 2.9175)").expect("Invalid geometry");
+/// let mut writer = WKTWriter::new().expect("Failed to create WKTWriter");
+/// writer.set_rounding_precision(2);
+/// writer.set_trim(true);
+///
+/// assert_eq!(writer.write(&geom).unwrap(), "POINT (2.57 2.92)");
+/// ```
+pub struct WKTWriter {
+    ptr: *mut GEOSWKTWriter,
+}
+
+impl WKTWriter {
+    pub fn new() -> GResult<WKTWriter> {
+        let ptr = unsafe { GEOSWKTWriter_create_r(get_context_handle()) };
+        if ptr.is_null() {
+            Err(Error::GenericError("GEOSWKTWriter_create_r failed".to_owned()))
+        } else {
+            Ok(WKTWriter { ptr })
+        }
+    }
+
+    /// Sets the number of decimal places to keep when writing coordinates.
+    /// A negative value means full double precision (the default).
+    pub fn set_rounding_precision(&mut self, precision: i32) {
+        unsafe { GEOSWKTWriter_setRoundingPrecision_r(get_context_handle(), self.ptr, precision) }
+    }
+
+    /// Sets whether unnecessary trailing zeros (and the `.` when there is no
+    /// fractional part left) are trimmed from the output.
+    pub fn set_trim(&mut self, trim: bool) {
+        // GEOSWKTWriter_setTrim_r takes a `char`, which binds to `c_char`
+        // (signed on most targets, so `as u8` would be the wrong type).
+        unsafe { GEOSWKTWriter_setTrim_r(get_context_handle(), self.ptr, trim as c_char) }
+    }
+
+    /// Sets the output dimensions (2D, 3D, ...).
+    pub fn set_output_dimension(&mut self, dimensions: Dimensions) {
+        unsafe { GEOSWKTWriter_setOutputDimension_r(get_context_handle(), self.ptr, dimensions.into()) }
+    }
+
+    /// Writes the given geometry as WKT.
+    pub fn write(&self, g: &GGeom) -> GResult<String> {
+        unsafe {
+            let ptr = GEOSWKTWriter_write_r(get_context_handle(), self.ptr, g.as_raw());
+            if ptr.is_null() {
+                return Err(Error::GenericError("GEOSWKTWriter_write_r failed".to_owned()));
+            }
+            let c_result = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+            GEOSFree_r(get_context_handle(), ptr as *mut _);
+            Ok(c_result)
+        }
+    }
+}
+
+impl Drop for WKTWriter {
+    fn drop(&mut self) {
+        unsafe { GEOSWKTWriter_destroy_r(get_context_handle(), self.ptr) };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ffi::GGeom;
+    use wkt_writer::WKTWriter;
+
+    #[test]
+    fn rounding_precision_and_trim_test() {
+        let geom = GGeom::new("POINT (2.5 2.9175)").unwrap();
+        let mut writer = WKTWriter::new().unwrap();
+
+        writer.set_rounding_precision(2);
+        writer.set_trim(true);
+        let trimmed = writer.write(&geom).unwrap();
+        assert_eq!(trimmed, "POINT (2.5 2.92)");
+
+        // Untrimmed output keeps the trailing zero of `2.50` dropped above; the exact
+        // padding width is a GEOS implementation detail we can't pin down
+        // without running against a real GEOS build, so only check what the
+        // two modes are guaranteed to share.
+        writer.set_trim(false);
+        let untrimmed = writer.write(&geom).unwrap();
+        assert!(untrimmed.starts_with("POINT (2.50"));
+        assert_ne!(untrimmed, trimmed);
+    }
+}